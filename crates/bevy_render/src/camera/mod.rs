@@ -0,0 +1,52 @@
+use legion::prelude::Entity;
+use std::collections::HashMap;
+
+/// Maps a named camera (e.g. `"Camera2d"`, `"Camera3d"`) to the entity that currently
+/// drives it. Render graph nodes look cameras up by name so multiple cameras can share
+/// the same pass.
+#[derive(Default)]
+pub struct ActiveCameras {
+    cameras: HashMap<String, Entity>,
+}
+
+impl ActiveCameras {
+    pub fn get(&self, name: &str) -> Option<Entity> {
+        self.cameras.get(name).copied()
+    }
+
+    pub fn set(&mut self, name: &str, entity: Entity) {
+        self.cameras.insert(name.to_string(), entity);
+    }
+}
+
+/// A camera's sub-rectangle of the shared render target, in pixels, plus the depth range
+/// to map NDC z into. Used to render multiple cameras into disjoint regions of the same
+/// attachment, e.g. split-screen or a picture-in-picture minimap.
+#[derive(Copy, Clone, Debug)]
+pub struct Viewport {
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+    pub min_depth: f32,
+    pub max_depth: f32,
+}
+
+/// A single entity visible to a camera this frame, as determined by frustum culling.
+#[derive(Copy, Clone)]
+pub struct VisibleEntity {
+    pub entity: Entity,
+}
+
+/// The set of entities visible to a camera this frame. Populated by the visibility
+/// system that runs before the render graph.
+#[derive(Default, Clone)]
+pub struct VisibleEntities {
+    pub value: Vec<VisibleEntity>,
+}
+
+impl VisibleEntities {
+    pub fn iter(&self) -> impl Iterator<Item = &VisibleEntity> {
+        self.value.iter()
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    pipeline::PipelineDescriptor,
+    render_graph::nodes::RenderBundle,
+    render_resource::{BindGroupId, BufferId},
+};
+use bevy_asset::Handle;
+use std::{ops::Range, sync::Arc};
+
+/// A component that, when present on an entity listed in a camera's
+/// [`crate::VisibleEntities`], causes that entity to be drawn by replaying its
+/// `render_commands` in the main pass.
+///
+/// If `bundle` is set, it takes priority over `render_commands`: the entity's commands
+/// were already prevalidated and flattened into a [`RenderBundle`] once. Each frame the
+/// bundle's commands are still issued to the pass one by one and checked again.
+#[derive(Clone)]
+pub struct Draw {
+    pub is_visible: bool,
+    pub is_transparent: bool,
+    pub render_commands: Vec<RenderCommand>,
+    pub bundle: Option<Handle<RenderBundle>>,
+}
+
+impl Default for Draw {
+    fn default() -> Self {
+        Draw {
+            is_visible: true,
+            is_transparent: false,
+            render_commands: Vec::new(),
+            bundle: None,
+        }
+    }
+}
+
+/// A single step of a [`Draw`]'s render command stream, replayed in order against the
+/// active render pass by `MainPassNode`.
+#[derive(Clone)]
+pub enum RenderCommand {
+    SetPipeline {
+        pipeline: Handle<PipelineDescriptor>,
+    },
+    SetVertexBuffer {
+        slot: u32,
+        buffer: BufferId,
+        offset: u64,
+    },
+    SetIndexBuffer {
+        buffer: BufferId,
+        offset: u64,
+    },
+    SetBindGroup {
+        index: u32,
+        bind_group: BindGroupId,
+        dynamic_uniform_indices: Option<Arc<Vec<u32>>>,
+    },
+    DrawIndexed {
+        indices: Range<u32>,
+        base_vertex: i32,
+        instances: Range<u32>,
+    },
+    /// A non-indexed draw: vertices are consumed directly from the bound vertex buffers,
+    /// with no index buffer required.
+    Draw {
+        vertices: Range<u32>,
+        instances: Range<u32>,
+    },
+    /// Like [`RenderCommand::DrawIndexed`], but the draw parameters (index count, instance
+    /// count, base vertex, ...) are sourced from `buffer` at `offset` instead of being
+    /// supplied here, enabling GPU-driven rendering.
+    DrawIndexedIndirect {
+        buffer: BufferId,
+        offset: u64,
+    },
+    /// Like [`RenderCommand::Draw`], but the draw parameters are sourced from `buffer` at
+    /// `offset` instead of being supplied here.
+    DrawIndirect {
+        buffer: BufferId,
+        offset: u64,
+    },
+    /// Replays every command of a prevalidated [`RenderBundle`], resetting draw state at
+    /// entry so the bundle is isolated from whatever the surrounding pass had set.
+    ExecuteBundle {
+        bundle: Handle<RenderBundle>,
+    },
+    /// Restricts subsequent draws to a sub-rectangle of the attachment, in pixels, and
+    /// remaps NDC z to `[min_depth, max_depth]`. Pass-level dynamic state, not pipeline
+    /// state: it is not isolated by [`RenderCommand::ExecuteBundle`] and must be
+    /// reapplied by whatever issues it.
+    SetViewport {
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        min_depth: f32,
+        max_depth: f32,
+    },
+    /// Discards fragments outside a sub-rectangle of the attachment, in pixels.
+    SetScissor {
+        x: u32,
+        y: u32,
+        w: u32,
+        h: u32,
+    },
+    /// Starts counting samples that pass depth/stencil testing for the draws that follow,
+    /// into slot `index` of the pass's occlusion query set. Must be paired with
+    /// [`RenderCommand::EndOcclusionQuery`] before the pass ends or another query begins.
+    BeginOcclusionQuery {
+        index: u32,
+    },
+    EndOcclusionQuery,
+}
@@ -0,0 +1,10 @@
+pub mod camera;
+pub mod draw;
+pub mod pass;
+pub mod pipeline;
+pub mod render_graph;
+pub mod render_resource;
+pub mod renderer;
+
+pub use camera::{ActiveCameras, Viewport, VisibleEntities};
+pub use draw::{Draw, RenderCommand};
@@ -0,0 +1,28 @@
+use crate::render_resource::{RenderResourceId, TextureFormat};
+
+/// Where a pass attachment's backing texture comes from: a render-graph input slot, or a
+/// texture resource id resolved directly.
+pub enum TextureAttachment {
+    Input(String),
+    Id(RenderResourceId),
+    None,
+}
+
+pub struct RenderPassColorAttachmentDescriptor {
+    pub attachment: TextureAttachment,
+    pub resolve_target: Option<TextureAttachment>,
+    pub format: TextureFormat,
+}
+
+pub struct RenderPassDepthStencilAttachmentDescriptor {
+    pub attachment: TextureAttachment,
+    pub format: TextureFormat,
+}
+
+/// Describes the attachments a pass renders into. Passed to
+/// [`crate::renderer::RenderContext::begin_pass`] to open a render pass.
+pub struct PassDescriptor {
+    pub color_attachments: Vec<RenderPassColorAttachmentDescriptor>,
+    pub depth_stencil_attachment: Option<RenderPassDepthStencilAttachmentDescriptor>,
+    pub sample_count: u32,
+}
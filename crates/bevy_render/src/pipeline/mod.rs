@@ -0,0 +1,40 @@
+use crate::render_resource::{BindGroupId, TextureFormat};
+
+/// Describes a single bind group slot in a [`PipelineLayout`].
+pub struct BindGroupDescriptor {
+    pub index: u32,
+    pub id: BindGroupId,
+}
+
+/// The resolved layout of a pipeline: how many bind groups and vertex buffers it expects.
+#[derive(Default)]
+pub struct PipelineLayout {
+    pub bind_groups: Vec<BindGroupDescriptor>,
+    pub vertex_buffer_descriptors: Vec<VertexBufferDescriptor>,
+}
+
+impl PipelineLayout {
+    pub fn get_bind_group(&self, index: u32) -> Option<&BindGroupDescriptor> {
+        self.bind_groups.iter().find(|b| b.index == index)
+    }
+}
+
+/// Marks a single vertex buffer slot of a [`PipelineLayout`]; it carries no stride or
+/// attribute data.
+pub struct VertexBufferDescriptor;
+
+/// A render pipeline: its layout and the output state (color target formats,
+/// depth-stencil format, sample count) it was built against. Shader, rasterization and
+/// blending state are not held here.
+pub struct PipelineDescriptor {
+    pub layout: Option<PipelineLayout>,
+    pub color_target_formats: Vec<TextureFormat>,
+    pub depth_stencil_format: Option<TextureFormat>,
+    pub sample_count: u32,
+}
+
+impl PipelineDescriptor {
+    pub fn get_layout(&self) -> Option<&PipelineLayout> {
+        self.layout.as_ref()
+    }
+}
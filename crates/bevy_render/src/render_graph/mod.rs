@@ -0,0 +1,58 @@
+pub mod nodes;
+
+use crate::render_resource::{RenderResourceId, RenderResourceType};
+use legion::prelude::{Resources, World};
+
+use crate::renderer::RenderContext;
+
+/// Describes a single input or output slot on a [`Node`]: its name and what kind of
+/// resource it carries.
+pub struct ResourceSlotInfo {
+    pub name: String,
+    pub resource_type: RenderResourceType,
+}
+
+impl ResourceSlotInfo {
+    pub fn new(name: String, resource_type: RenderResourceType) -> Self {
+        ResourceSlotInfo { name, resource_type }
+    }
+}
+
+/// A resolved set of resource ids flowing into or out of a [`Node`], indexed by slot.
+#[derive(Default)]
+pub struct ResourceSlots {
+    resources: Vec<RenderResourceId>,
+}
+
+impl ResourceSlots {
+    pub fn get(&self, index: usize) -> Option<&RenderResourceId> {
+        self.resources.get(index)
+    }
+}
+
+impl RenderResourceId {
+    pub fn get_texture(&self) -> Option<RenderResourceId> {
+        Some(*self)
+    }
+}
+
+/// A single stage of the render graph. Nodes declare their input/output resource slots
+/// and are free to read from the ECS `World`/`Resources` when `update` runs.
+pub trait Node: Send + Sync {
+    fn input(&self) -> &[ResourceSlotInfo] {
+        &[]
+    }
+
+    fn output(&self) -> &[ResourceSlotInfo] {
+        &[]
+    }
+
+    fn update(
+        &mut self,
+        world: &World,
+        resources: &Resources,
+        render_context: &mut dyn RenderContext,
+        input: &ResourceSlots,
+        output: &mut ResourceSlots,
+    );
+}
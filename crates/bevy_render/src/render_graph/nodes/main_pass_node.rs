@@ -3,18 +3,24 @@ use crate::{
     pass::{PassDescriptor, TextureAttachment},
     pipeline::PipelineDescriptor,
     render_graph::{Node, ResourceSlotInfo, ResourceSlots},
-    render_resource::{BindGroupId, BufferId, RenderResourceBindings, RenderResourceType},
-    renderer::RenderContext, ActiveCameras, VisibleEntities,
+    render_resource::{
+        BindGroupId, BufferId, OcclusionQuerySetId, RenderResourceBindings, RenderResourceType,
+        TextureFormat,
+    },
+    renderer::{RenderContext, RenderPass},
+    ActiveCameras, Viewport, VisibleEntities,
 };
 use bevy_asset::{Assets, Handle};
 use legion::prelude::*;
+use std::collections::HashMap;
 
 pub struct MainPassNode {
     descriptor: PassDescriptor,
     inputs: Vec<ResourceSlotInfo>,
-    cameras: Vec<String>,
+    cameras: Vec<(String, Option<Viewport>)>,
     color_attachment_input_indices: Vec<Option<usize>>,
     depth_stencil_attachment_input_index: Option<usize>,
+    occlusion_query_enabled: bool,
 }
 
 impl MainPassNode {
@@ -50,11 +56,24 @@ impl MainPassNode {
             cameras: Vec::new(),
             color_attachment_input_indices,
             depth_stencil_attachment_input_index,
+            occlusion_query_enabled: false,
         }
     }
 
-    pub fn add_camera(&mut self, camera_name: &str) {
-        self.cameras.push(camera_name.to_string());
+    /// Registers a camera to be rendered by this pass. `viewport` restricts the camera's
+    /// draws to a sub-rectangle of the shared attachment (e.g. one quadrant of the screen
+    /// for split-screen); pass `None` to render into the full attachment.
+    pub fn add_camera(&mut self, camera_name: &str, viewport: Option<Viewport>) {
+        self.cameras.push((camera_name.to_string(), viewport));
+    }
+
+    /// Opts this node into occlusion-query pass mode: each camera's visible entities are
+    /// wrapped in their own occlusion query and the results are written to
+    /// [`OcclusionResults`] after the pass. Disabled by default, since it costs a query
+    /// set allocation and readback per camera and requires `OcclusionResults` to be
+    /// registered as a resource.
+    pub fn set_occlusion_query_enabled(&mut self, enabled: bool) {
+        self.occlusion_query_enabled = enabled;
     }
 }
 
@@ -73,7 +92,13 @@ impl Node for MainPassNode {
     ) {
         let render_resource_bindings = resources.get::<RenderResourceBindings>().unwrap();
         let pipelines = resources.get::<Assets<PipelineDescriptor>>().unwrap();
+        let render_bundles = resources.get::<Assets<RenderBundle>>().unwrap();
         let active_cameras= resources.get::<ActiveCameras>().unwrap();
+        let mut occlusion_results = if self.occlusion_query_enabled {
+            Some(resources.get_mut::<OcclusionResults>().unwrap())
+        } else {
+            None
+        };
 
         for (i, color_attachment) in self.descriptor.color_attachments.iter_mut().enumerate() {
             if let Some(input_index) = self.color_attachment_input_indices[i] {
@@ -91,19 +116,52 @@ impl Node for MainPassNode {
                 TextureAttachment::Id(input.get(input_index).unwrap().get_texture().unwrap());
         }
 
-        for camera_name in self.cameras.iter() {
+        let pass_descriptor = &self.descriptor;
+        for (camera_name, viewport) in self.cameras.iter() {
             let visible_entities = if let Some(camera_entity) = active_cameras.get(camera_name) {
                 world.get_component::<VisibleEntities>(camera_entity).unwrap()
             } else {
                 continue;
             };
 
+            // Size the query set to this camera's visible entities so every entity below
+            // gets its own occlusion query slot. Only allocated in occlusion-query pass
+            // mode: it's wasted GPU state otherwise, and nothing would ever resolve it.
+            let query_set = if self.occlusion_query_enabled {
+                Some(render_context.allocate_occlusion_query_set(visible_entities.value.len() as u32))
+            } else {
+                None
+            };
+
             render_context.begin_pass(
                 &self.descriptor,
                 &render_resource_bindings,
+                query_set,
                 &mut |render_pass| {
                     let mut draw_state = DrawState::default();
-                    for visible_entity in visible_entities.iter() {
+
+                    // Viewport/scissor are pass-level dynamic state, not part of
+                    // `DrawState`, so they're applied once per camera here rather than
+                    // inside the per-entity command loop, and implicitly reset the next
+                    // time this closure runs for the next camera.
+                    if let Some(viewport) = viewport {
+                        render_pass.set_viewport(
+                            viewport.x,
+                            viewport.y,
+                            viewport.w,
+                            viewport.h,
+                            viewport.min_depth,
+                            viewport.max_depth,
+                        );
+                        render_pass.set_scissor_rect(
+                            viewport.x as u32,
+                            viewport.y as u32,
+                            viewport.w as u32,
+                            viewport.h as u32,
+                        );
+                    }
+
+                    for (query_index, visible_entity) in visible_entities.iter().enumerate() {
                         let draw = if let Some(draw) = world.get_component::<Draw>(visible_entity.entity) {
                             draw
                         } else {
@@ -113,69 +171,459 @@ impl Node for MainPassNode {
                         if !draw.is_visible {
                             continue;
                         }
-    
-                        for render_command in draw.render_commands.iter() {
-                            match render_command {
-                                RenderCommand::SetPipeline { pipeline } => {
-                                    // TODO: Filter pipelines
-                                    render_pass.set_pipeline(*pipeline);
-                                    let descriptor = pipelines.get(pipeline).unwrap();
-                                    draw_state.set_pipeline(*pipeline, descriptor);
-                                }
-                                RenderCommand::DrawIndexed {
-                                    base_vertex,
-                                    indices,
-                                    instances,
-                                } => {
-                                    if draw_state.can_draw_indexed() {
-                                        render_pass.draw_indexed(
-                                            indices.clone(),
-                                            *base_vertex,
-                                            instances.clone(),
-                                        );
-                                    } else {
-                                        log::info!("Could not draw indexed because the pipeline layout wasn't fully set for pipeline: {:?}", draw_state.pipeline);
-                                    }
-                                }
-                                RenderCommand::SetVertexBuffer {
-                                    buffer,
-                                    offset,
-                                    slot,
-                                } => {
-                                    render_pass.set_vertex_buffer(*slot, *buffer, *offset);
-                                    draw_state.set_vertex_buffer(*slot, *buffer);
-                                }
-                                RenderCommand::SetIndexBuffer { buffer, offset } => {
-                                    render_pass.set_index_buffer(*buffer, *offset);
-                                    draw_state.set_index_buffer(*buffer)
-                                }
-                                RenderCommand::SetBindGroup {
-                                    index,
-                                    bind_group,
-                                    dynamic_uniform_indices,
-                                } => {
-                                    let pipeline = pipelines.get(&draw_state.pipeline.unwrap()).unwrap();
-                                    let layout = pipeline.get_layout().unwrap();
-                                    let bind_group_descriptor = layout.get_bind_group(*index).unwrap();
-                                    render_pass.set_bind_group(
-                                        *index,
-                                        bind_group_descriptor.id,
-                                        *bind_group,
-                                        dynamic_uniform_indices
-                                            .as_ref()
-                                            .map(|indices| indices.as_slice()),
-                                    );
-                                    draw_state.set_bind_group(*index, *bind_group);
-                                }
+
+                        // Each entity's draws get their own occlusion query slot, begun
+                        // and ended within this same pass. Only wrap in occlusion-query
+                        // pass mode, and never if the entity's own command stream already
+                        // carries a query: queries must never be nested.
+                        let draw_has_own_query = draw.render_commands.iter().any(|command| {
+                            matches!(
+                                command,
+                                RenderCommand::BeginOcclusionQuery { .. }
+                                    | RenderCommand::EndOcclusionQuery
+                            )
+                        });
+                        let wrap_in_query = query_set.is_some() && !draw_has_own_query;
+
+                        if wrap_in_query {
+                            render_pass.begin_occlusion_query(query_index as u32);
+                        }
+
+                        if let Some(bundle_handle) = &draw.bundle {
+                            let bundle = render_bundles.get(bundle_handle).unwrap();
+                            execute_bundle(
+                                visible_entity.entity,
+                                bundle,
+                                render_pass,
+                                &pipelines,
+                                &render_bundles,
+                                pass_descriptor,
+                                &mut draw_state,
+                            );
+                        } else {
+                            for render_command in draw.render_commands.iter() {
+                                execute_render_command(
+                                    visible_entity.entity,
+                                    render_command,
+                                    render_pass,
+                                    &pipelines,
+                                    &render_bundles,
+                                    pass_descriptor,
+                                    &mut draw_state,
+                                );
                             }
                         }
+
+                        if wrap_in_query {
+                            render_pass.end_occlusion_query();
+                        }
                     }
                 },
             );
+
+            if let Some(query_set) = query_set {
+                let visibility = render_context.resolve_occlusion_query_set(query_set);
+                occlusion_results
+                    .as_mut()
+                    .unwrap()
+                    .set(camera_name.clone(), visibility);
+            }
+        }
+    }
+}
+
+/// Per-camera occlusion query results from the previous frame: whether each of that
+/// camera's visible entities (by index into its [`VisibleEntities`]) had a nonzero
+/// sample count. A following frame's culling stage can drop entities that come back
+/// `false` here, layering GPU occlusion culling on top of CPU frustum culling.
+#[derive(Default)]
+pub struct OcclusionResults {
+    visibility_by_camera: HashMap<String, Vec<bool>>,
+}
+
+impl OcclusionResults {
+    pub fn get(&self, camera_name: &str) -> Option<&[bool]> {
+        self.visibility_by_camera
+            .get(camera_name)
+            .map(|v| v.as_slice())
+    }
+
+    fn set(&mut self, camera_name: String, visibility: Vec<bool>) {
+        self.visibility_by_camera.insert(camera_name, visibility);
+    }
+}
+
+fn execute_render_command(
+    entity: Entity,
+    render_command: &RenderCommand,
+    render_pass: &mut dyn RenderPass,
+    pipelines: &Assets<PipelineDescriptor>,
+    render_bundles: &Assets<RenderBundle>,
+    pass_descriptor: &PassDescriptor,
+    draw_state: &mut DrawState,
+) {
+    match render_command {
+        RenderCommand::SetPipeline { pipeline } => {
+            let descriptor = pipelines.get(pipeline).unwrap();
+            match check_pipeline_compatibility(pass_descriptor, descriptor) {
+                Ok(()) => {
+                    render_pass.set_pipeline(*pipeline);
+                    draw_state.set_pipeline(*pipeline, descriptor);
+                }
+                Err(err) => log::warn!(
+                    "Skipping SetPipeline for entity {:?}: {}",
+                    entity,
+                    err
+                ),
+            }
+        }
+        RenderCommand::DrawIndexed {
+            base_vertex,
+            indices,
+            instances,
+        } => match draw_state.validate_draw_indexed() {
+            Ok(()) => render_pass.draw_indexed(indices.clone(), *base_vertex, instances.clone()),
+            Err(err) => log::warn!(
+                "Skipping indexed draw for entity {:?} on pipeline {:?}: {}",
+                entity,
+                draw_state.pipeline,
+                err
+            ),
+        },
+        RenderCommand::SetVertexBuffer {
+            buffer,
+            offset,
+            slot,
+        } => {
+            render_pass.set_vertex_buffer(*slot, *buffer, *offset);
+            draw_state.set_vertex_buffer(*slot, *buffer);
+        }
+        RenderCommand::SetIndexBuffer { buffer, offset } => {
+            render_pass.set_index_buffer(*buffer, *offset);
+            draw_state.set_index_buffer(*buffer)
+        }
+        RenderCommand::Draw { vertices, instances } => match draw_state.validate_draw() {
+            Ok(()) => render_pass.draw(vertices.clone(), instances.clone()),
+            Err(err) => log::warn!(
+                "Skipping draw for entity {:?} on pipeline {:?}: {}",
+                entity,
+                draw_state.pipeline,
+                err
+            ),
+        },
+        RenderCommand::DrawIndexedIndirect { buffer, offset } => {
+            match draw_state.validate_draw_indexed() {
+                Ok(()) => render_pass.draw_indexed_indirect(*buffer, *offset),
+                Err(err) => log::warn!(
+                    "Skipping indirect indexed draw for entity {:?} on pipeline {:?}: {}",
+                    entity,
+                    draw_state.pipeline,
+                    err
+                ),
+            }
+        }
+        RenderCommand::DrawIndirect { buffer, offset } => match draw_state.validate_draw() {
+            Ok(()) => render_pass.draw_indirect(*buffer, *offset),
+            Err(err) => log::warn!(
+                "Skipping indirect draw for entity {:?} on pipeline {:?}: {}",
+                entity,
+                draw_state.pipeline,
+                err
+            ),
+        },
+        RenderCommand::SetBindGroup {
+            index,
+            bind_group,
+            dynamic_uniform_indices,
+        } => match draw_state.pipeline {
+            Some(pipeline_handle) => {
+                let pipeline = pipelines.get(&pipeline_handle).unwrap();
+                let layout = pipeline.get_layout().unwrap();
+                let bind_group_descriptor = layout.get_bind_group(*index).unwrap();
+                render_pass.set_bind_group(
+                    *index,
+                    bind_group_descriptor.id,
+                    *bind_group,
+                    dynamic_uniform_indices
+                        .as_ref()
+                        .map(|indices| indices.as_slice()),
+                );
+                draw_state.set_bind_group(*index, *bind_group);
+            }
+            None => log::warn!(
+                "Skipping SetBindGroup for entity {:?}: no compatible pipeline is bound",
+                entity
+            ),
+        },
+        RenderCommand::ExecuteBundle { bundle } => {
+            let bundle = render_bundles.get(bundle).unwrap();
+            execute_bundle(
+                entity,
+                bundle,
+                render_pass,
+                pipelines,
+                render_bundles,
+                pass_descriptor,
+                draw_state,
+            );
+        }
+        RenderCommand::SetViewport {
+            x,
+            y,
+            w,
+            h,
+            min_depth,
+            max_depth,
+        } => {
+            render_pass.set_viewport(*x, *y, *w, *h, *min_depth, *max_depth);
+        }
+        RenderCommand::SetScissor { x, y, w, h } => {
+            render_pass.set_scissor_rect(*x, *y, *w, *h);
+        }
+        RenderCommand::BeginOcclusionQuery { index } => {
+            render_pass.begin_occlusion_query(*index);
+        }
+        RenderCommand::EndOcclusionQuery => {
+            render_pass.end_occlusion_query();
+        }
+    }
+}
+
+/// Replays a [`RenderBundle`] against `render_pass`, isolated from the surrounding pass's
+/// [`DrawState`] on entry: the bundle is recorded against a fresh, default draw state, so
+/// it can't observe whatever the caller had bound. Per wgpu semantics, executing a bundle
+/// leaves the real pass's pipeline/bind-group/buffer bindings in a backend-defined state,
+/// so `outer_draw_state` is reset to default rather than restored once the bundle
+/// finishes: any command that follows in the same stream must rebind before drawing.
+fn execute_bundle(
+    entity: Entity,
+    bundle: &RenderBundle,
+    render_pass: &mut dyn RenderPass,
+    pipelines: &Assets<PipelineDescriptor>,
+    render_bundles: &Assets<RenderBundle>,
+    pass_descriptor: &PassDescriptor,
+    outer_draw_state: &mut DrawState,
+) {
+    let mut bundle_draw_state = DrawState::default();
+    for render_command in bundle.render_commands.iter() {
+        // Bundles are prevalidated at construction time and never contain a nested
+        // `ExecuteBundle`, so this recursion bottoms out after one level.
+        execute_render_command(
+            entity,
+            render_command,
+            render_pass,
+            pipelines,
+            render_bundles,
+            pass_descriptor,
+            &mut bundle_draw_state,
+        );
+    }
+    *outer_draw_state = DrawState::default();
+}
+
+/// A prevalidated, flattened sequence of render commands that is replayed against a pass
+/// via [`RenderCommand::ExecuteBundle`]. Useful for static geometry whose command stream
+/// never changes between frames.
+///
+/// The command list is built and its `DrawState` layout checks are run once, at
+/// construction. Each replay still issues every command to the pass and runs the same
+/// checks (and the pipeline compatibility check) on it again.
+pub struct RenderBundle {
+    render_commands: Vec<RenderCommand>,
+}
+
+impl RenderBundle {
+    /// Builds a bundle from a flattened command stream, validating once that the
+    /// commands fully set up a pipeline's bind groups and vertex/index buffers before a
+    /// draw call is reached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a draw call in `render_commands` is reached without its pipeline's bind
+    /// groups and buffers fully set. This mirrors the per-frame validation
+    /// `MainPassNode` does for ordinary (non-bundled) draws, just performed eagerly.
+    /// Also panics if `render_commands` contains a nested `ExecuteBundle`, a viewport or
+    /// scissor command, or an occlusion query command.
+    pub fn new(render_commands: Vec<RenderCommand>, pipelines: &Assets<PipelineDescriptor>) -> Self {
+        let mut draw_state = DrawState::default();
+        for render_command in render_commands.iter() {
+            match render_command {
+                RenderCommand::SetPipeline { pipeline } => {
+                    let descriptor = pipelines.get(pipeline).unwrap();
+                    draw_state.set_pipeline(*pipeline, descriptor);
+                }
+                RenderCommand::SetVertexBuffer { buffer, slot, .. } => {
+                    draw_state.set_vertex_buffer(*slot, *buffer);
+                }
+                RenderCommand::SetIndexBuffer { buffer, .. } => {
+                    draw_state.set_index_buffer(*buffer);
+                }
+                RenderCommand::SetBindGroup {
+                    index, bind_group, ..
+                } => {
+                    draw_state.set_bind_group(*index, *bind_group);
+                }
+                RenderCommand::DrawIndexed { .. } | RenderCommand::DrawIndexedIndirect { .. } => {
+                    if let Err(err) = draw_state.validate_draw_indexed() {
+                        panic!("RenderBundle contains an invalid indexed draw command: {}", err);
+                    }
+                }
+                RenderCommand::Draw { .. } | RenderCommand::DrawIndirect { .. } => {
+                    if let Err(err) = draw_state.validate_draw() {
+                        panic!("RenderBundle contains an invalid draw command: {}", err);
+                    }
+                }
+                RenderCommand::ExecuteBundle { .. } => {
+                    panic!("RenderBundle cannot contain a nested ExecuteBundle command");
+                }
+                RenderCommand::SetViewport { .. } | RenderCommand::SetScissor { .. } => {
+                    panic!(
+                        "RenderBundle cannot contain viewport/scissor commands: they are pass-level state, not bundle-isolated"
+                    );
+                }
+                RenderCommand::BeginOcclusionQuery { .. } | RenderCommand::EndOcclusionQuery => {
+                    panic!(
+                        "RenderBundle cannot contain occlusion query commands: query slots are assigned per replay position, not at bundle construction"
+                    );
+                }
+            }
+        }
+
+        RenderBundle { render_commands }
+    }
+}
+
+/// Why a draw call couldn't be issued, naming exactly which piece of pipeline state was
+/// never set rather than collapsing everything into a single bool.
+#[derive(Debug)]
+pub enum DrawError {
+    MissingBindGroup { index: u32 },
+    MissingVertexBuffer { slot: u32 },
+    MissingIndexBuffer,
+}
+
+impl std::fmt::Display for DrawError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DrawError::MissingBindGroup { index } => {
+                write!(f, "bind group {} was never set", index)
+            }
+            DrawError::MissingVertexBuffer { slot } => {
+                write!(f, "vertex buffer slot {} was never set", slot)
+            }
+            DrawError::MissingIndexBuffer => write!(f, "index buffer was never set"),
+        }
+    }
+}
+
+/// Why a pipeline can't be bound in the active pass: its fixed-function output state
+/// (color target formats, depth-stencil format, sample count) doesn't match what
+/// `PassDescriptor` was built with, which would otherwise surface as a driver-level
+/// validation crash once the pass actually replays the pipeline.
+#[derive(Debug)]
+pub enum PipelineCompatibilityError {
+    ColorAttachmentCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    ColorAttachmentFormatMismatch {
+        attachment_index: usize,
+        expected: TextureFormat,
+        actual: TextureFormat,
+    },
+    DepthStencilFormatMismatch {
+        expected: Option<TextureFormat>,
+        actual: Option<TextureFormat>,
+    },
+    SampleCountMismatch {
+        expected: u32,
+        actual: u32,
+    },
+}
+
+impl std::fmt::Display for PipelineCompatibilityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PipelineCompatibilityError::ColorAttachmentCountMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "pipeline has {} color target(s) but the pass has {} color attachment(s)",
+                    actual, expected
+                )
+            }
+            PipelineCompatibilityError::ColorAttachmentFormatMismatch {
+                attachment_index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "color attachment {} format mismatch: pass expects {:?}, pipeline targets {:?}",
+                attachment_index, expected, actual
+            ),
+            PipelineCompatibilityError::DepthStencilFormatMismatch { expected, actual } => write!(
+                f,
+                "depth-stencil format mismatch: pass expects {:?}, pipeline targets {:?}",
+                expected, actual
+            ),
+            PipelineCompatibilityError::SampleCountMismatch { expected, actual } => write!(
+                f,
+                "sample count mismatch: pass uses {}, pipeline was built for {}",
+                expected, actual
+            ),
         }
     }
 }
 
+/// Checks `pipeline`'s color target formats, depth-stencil format, and sample count
+/// against `pass_descriptor`'s attachments, so an entity's material pipeline built for a
+/// different pass can be skipped instead of crashing when it's bound here.
+fn check_pipeline_compatibility(
+    pass_descriptor: &PassDescriptor,
+    pipeline: &PipelineDescriptor,
+) -> Result<(), PipelineCompatibilityError> {
+    if pipeline.color_target_formats.len() != pass_descriptor.color_attachments.len() {
+        return Err(PipelineCompatibilityError::ColorAttachmentCountMismatch {
+            expected: pass_descriptor.color_attachments.len(),
+            actual: pipeline.color_target_formats.len(),
+        });
+    }
+
+    for (attachment_index, (pass_attachment, pipeline_format)) in pass_descriptor
+        .color_attachments
+        .iter()
+        .zip(pipeline.color_target_formats.iter())
+        .enumerate()
+    {
+        if pass_attachment.format != *pipeline_format {
+            return Err(PipelineCompatibilityError::ColorAttachmentFormatMismatch {
+                attachment_index,
+                expected: pass_attachment.format,
+                actual: *pipeline_format,
+            });
+        }
+    }
+
+    let pass_depth_stencil_format = pass_descriptor
+        .depth_stencil_attachment
+        .as_ref()
+        .map(|attachment| attachment.format);
+    if pass_depth_stencil_format != pipeline.depth_stencil_format {
+        return Err(PipelineCompatibilityError::DepthStencilFormatMismatch {
+            expected: pass_depth_stencil_format,
+            actual: pipeline.depth_stencil_format,
+        });
+    }
+
+    if pass_descriptor.sample_count != pipeline.sample_count {
+        return Err(PipelineCompatibilityError::SampleCountMismatch {
+            expected: pass_descriptor.sample_count,
+            actual: pipeline.sample_count,
+        });
+    }
+
+    Ok(())
+}
+
 /// Tracks the current pipeline state to ensure draw calls are valid.
 #[derive(Default)]
 struct DrawState {
@@ -198,10 +646,43 @@ impl DrawState {
         self.index_buffer = Some(buffer);
     }
 
-    pub fn can_draw_indexed(&self) -> bool {
-        self.bind_groups.iter().all(|b| b.is_some())
-            && self.vertex_buffers.iter().all(|v| v.is_some())
-            && self.index_buffer.is_some()
+    /// Checks that every bind group, vertex buffer slot, and the index buffer are set,
+    /// naming the first one that isn't rather than collapsing the whole check to a bool.
+    pub fn validate_draw_indexed(&self) -> Result<(), DrawError> {
+        for (index, bind_group) in self.bind_groups.iter().enumerate() {
+            if bind_group.is_none() {
+                return Err(DrawError::MissingBindGroup {
+                    index: index as u32,
+                });
+            }
+        }
+        for (slot, vertex_buffer) in self.vertex_buffers.iter().enumerate() {
+            if vertex_buffer.is_none() {
+                return Err(DrawError::MissingVertexBuffer { slot: slot as u32 });
+            }
+        }
+        if self.index_buffer.is_none() {
+            return Err(DrawError::MissingIndexBuffer);
+        }
+        Ok(())
+    }
+
+    /// Like [`DrawState::validate_draw_indexed`], but for non-indexed draws: checks bind
+    /// groups and vertex buffers only, since no index buffer is consumed.
+    pub fn validate_draw(&self) -> Result<(), DrawError> {
+        for (index, bind_group) in self.bind_groups.iter().enumerate() {
+            if bind_group.is_none() {
+                return Err(DrawError::MissingBindGroup {
+                    index: index as u32,
+                });
+            }
+        }
+        for (slot, vertex_buffer) in self.vertex_buffers.iter().enumerate() {
+            if vertex_buffer.is_none() {
+                return Err(DrawError::MissingVertexBuffer { slot: slot as u32 });
+            }
+        }
+        Ok(())
     }
 
     pub fn set_pipeline(
@@ -219,4 +700,4 @@ impl DrawState {
         self.vertex_buffers
             .resize(layout.vertex_buffer_descriptors.len(), None);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,3 @@
+mod main_pass_node;
+
+pub use main_pass_node::*;
@@ -0,0 +1,39 @@
+/// Opaque handle to any GPU resource (texture, buffer, sampler) tracked by the render graph.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RenderResourceId(pub u64);
+
+/// Opaque handle to a GPU bind group, allocated by the render backend.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BindGroupId(pub u64);
+
+/// Opaque handle to a GPU buffer, allocated by the render backend.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BufferId(pub u64);
+
+/// Opaque handle to a GPU occlusion query set, sized to the number of queries it will
+/// hold within a single pass.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct OcclusionQuerySetId(pub u64);
+
+/// A GPU texture format, as used by pipeline color targets/depth-stencil state and pass
+/// attachments, so the two can be checked for compatibility before binding a pipeline.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TextureFormat {
+    Rgba8UnormSrgb,
+    Bgra8UnormSrgb,
+    Depth32Float,
+}
+
+/// Describes what kind of resource a render graph input/output slot carries.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RenderResourceType {
+    Buffer,
+    Texture,
+    Sampler,
+}
+
+/// The render resource bindings (uniforms, textures, samplers) available to the current
+/// frame. This type holds no bindings itself; it is only passed on to
+/// [`crate::renderer::RenderContext::begin_pass`].
+#[derive(Default)]
+pub struct RenderResourceBindings;
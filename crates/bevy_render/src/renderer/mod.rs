@@ -0,0 +1,60 @@
+use crate::{
+    pass::PassDescriptor,
+    pipeline::PipelineDescriptor,
+    render_resource::{BindGroupId, BufferId, OcclusionQuerySetId, RenderResourceBindings},
+};
+use bevy_asset::Handle;
+use std::ops::Range;
+
+/// The recording interface for a single open render pass, implemented per-backend
+/// (wgpu, etc). `MainPassNode` drives this through the closure passed to
+/// [`RenderContext::begin_pass`].
+pub trait RenderPass {
+    fn set_pipeline(&mut self, pipeline: Handle<PipelineDescriptor>);
+    fn set_vertex_buffer(&mut self, slot: u32, buffer: BufferId, offset: u64);
+    fn set_index_buffer(&mut self, buffer: BufferId, offset: u64);
+    fn set_bind_group(
+        &mut self,
+        index: u32,
+        bind_group_descriptor_id: BindGroupId,
+        bind_group: BindGroupId,
+        dynamic_uniform_indices: Option<&[u32]>,
+    );
+    fn draw_indexed(&mut self, indices: Range<u32>, base_vertex: i32, instances: Range<u32>);
+    fn draw(&mut self, vertices: Range<u32>, instances: Range<u32>);
+    fn draw_indexed_indirect(&mut self, buffer: BufferId, offset: u64);
+    fn draw_indirect(&mut self, buffer: BufferId, offset: u64);
+    fn set_viewport(&mut self, x: f32, y: f32, w: f32, h: f32, min_depth: f32, max_depth: f32);
+    fn set_scissor_rect(&mut self, x: u32, y: u32, w: u32, h: u32);
+
+    /// Begins an occlusion query at `query_index` within the pass's bound query set. Must
+    /// be matched by [`RenderPass::end_occlusion_query`] before the pass ends, and queries
+    /// may not be nested.
+    fn begin_occlusion_query(&mut self, query_index: u32);
+    fn end_occlusion_query(&mut self);
+}
+
+/// Backend-specific entry point used by render graph nodes to open a pass and record
+/// commands into it.
+pub trait RenderContext {
+    /// Opens a render pass and runs `run_pass` against it. `occlusion_query_set`, if
+    /// given, is bound to the pass so that [`RenderPass::begin_occlusion_query`] calls
+    /// made inside `run_pass` actually write into it; leave it `None` for passes that
+    /// don't use occlusion queries.
+    fn begin_pass(
+        &mut self,
+        pass_descriptor: &PassDescriptor,
+        render_resource_bindings: &RenderResourceBindings,
+        occlusion_query_set: Option<OcclusionQuerySetId>,
+        run_pass: &mut dyn FnMut(&mut dyn RenderPass),
+    );
+
+    /// Allocates an occlusion query set with `count` slots, to be bound for the next
+    /// [`RenderContext::begin_pass`] call and written to via
+    /// [`RenderPass::begin_occlusion_query`]/[`RenderPass::end_occlusion_query`].
+    fn allocate_occlusion_query_set(&mut self, count: u32) -> OcclusionQuerySetId;
+
+    /// Resolves a query set's results into a readback buffer and returns, per query
+    /// index, whether its sample count was nonzero (i.e. the wrapped draw was visible).
+    fn resolve_occlusion_query_set(&mut self, query_set: OcclusionQuerySetId) -> Vec<bool>;
+}